@@ -0,0 +1,101 @@
+/// The public-key algorithm of a key, signature, or session-key packet.
+///
+/// IDs 100-110 are reserved for private/experimental use and never fail to parse;
+/// any other ID this crate does not recognize (e.g. a newly standardized algorithm)
+/// parses as `Unknown` rather than being dropped, so callers can still round-trip the
+/// packet and make a capability decision via `PublicKeyTrait::signing_capability`/
+/// `encryption_capability`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PublicKeyAlgorithm {
+    RSA,
+    RSAEncrypt,
+    RSASign,
+    Elgamal,
+    ElgamalEncrypt,
+    DSA,
+    ECDH,
+    ECDSA,
+    DiffieHellman,
+    EdDSALegacy,
+    Ed25519,
+    Ed448,
+    X25519,
+    X448,
+    /// Reserved for private or experimental use (IDs 100-110).
+    Private(u8),
+    /// An algorithm ID this crate does not (yet) recognize.
+    Unknown(u8),
+}
+
+impl From<u8> for PublicKeyAlgorithm {
+    fn from(id: u8) -> Self {
+        match id {
+            1 => Self::RSA,
+            2 => Self::RSAEncrypt,
+            3 => Self::RSASign,
+            16 => Self::Elgamal,
+            17 => Self::DSA,
+            18 => Self::ECDH,
+            19 => Self::ECDSA,
+            20 => Self::ElgamalEncrypt,
+            21 => Self::DiffieHellman,
+            22 => Self::EdDSALegacy,
+            25 => Self::X25519,
+            26 => Self::X448,
+            27 => Self::Ed25519,
+            28 => Self::Ed448,
+            100..=110 => Self::Private(id),
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+impl From<PublicKeyAlgorithm> for u8 {
+    fn from(algorithm: PublicKeyAlgorithm) -> Self {
+        match algorithm {
+            PublicKeyAlgorithm::RSA => 1,
+            PublicKeyAlgorithm::RSAEncrypt => 2,
+            PublicKeyAlgorithm::RSASign => 3,
+            PublicKeyAlgorithm::Elgamal => 16,
+            PublicKeyAlgorithm::DSA => 17,
+            PublicKeyAlgorithm::ECDH => 18,
+            PublicKeyAlgorithm::ECDSA => 19,
+            PublicKeyAlgorithm::ElgamalEncrypt => 20,
+            PublicKeyAlgorithm::DiffieHellman => 21,
+            PublicKeyAlgorithm::EdDSALegacy => 22,
+            PublicKeyAlgorithm::X25519 => 25,
+            PublicKeyAlgorithm::X448 => 26,
+            PublicKeyAlgorithm::Ed25519 => 27,
+            PublicKeyAlgorithm::Ed448 => 28,
+            PublicKeyAlgorithm::Private(id) | PublicKeyAlgorithm::Unknown(id) => id,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PublicKeyAlgorithm;
+
+    #[test]
+    fn known_ids_round_trip() {
+        for id in [1, 2, 3, 16, 17, 18, 19, 20, 21, 22, 25, 26, 27, 28] {
+            assert_eq!(u8::from(PublicKeyAlgorithm::from(id)), id);
+        }
+    }
+
+    #[test]
+    fn private_range_round_trips_as_private() {
+        for id in 100..=110 {
+            assert_eq!(PublicKeyAlgorithm::from(id), PublicKeyAlgorithm::Private(id));
+            assert_eq!(u8::from(PublicKeyAlgorithm::from(id)), id);
+        }
+    }
+
+    #[test]
+    fn unrecognized_ids_round_trip_as_unknown() {
+        for id in [0, 4, 15, 23, 29, 99, 111, 150, 255] {
+            assert_eq!(PublicKeyAlgorithm::from(id), PublicKeyAlgorithm::Unknown(id));
+            assert_eq!(u8::from(PublicKeyAlgorithm::from(id)), id);
+        }
+    }
+}
@@ -0,0 +1,22 @@
+/// The fingerprint of a key, as computed by [`crate::types::PublicKeyTrait::fingerprint`].
+///
+/// The width depends on the key version: v3 fingerprints are a 16-byte MD5 hash, v4
+/// fingerprints are a 20-byte SHA-1 hash, and v6 fingerprints are carried in full as
+/// the 32-byte SHA-256 hash (unlike v4, v6 fingerprints are never truncated).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Fingerprint {
+    V3([u8; 16]),
+    V4([u8; 20]),
+    V6([u8; 32]),
+}
+
+impl Fingerprint {
+    /// The raw fingerprint bytes, whatever the key version.
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            Self::V3(b) => b.as_slice(),
+            Self::V4(b) => b.as_slice(),
+            Self::V6(b) => b.as_slice(),
+        }
+    }
+}
@@ -4,16 +4,26 @@ use rand::{CryptoRng, Rng};
 
 use crate::crypto::hash::HashAlgorithm;
 use crate::crypto::public_key::PublicKeyAlgorithm;
+use crate::crypto::sym::SymmetricKeyAlgorithm;
 use crate::errors::Result;
 use crate::types::PkeskBytes;
+use crate::types::SecretKeyTrait;
 use crate::types::{EskType, Fingerprint, KeyId, KeyVersion, PublicParams, SignatureBytes};
 
 pub trait PublicKeyTrait: std::fmt::Debug {
     fn version(&self) -> KeyVersion;
 
+    /// The fingerprint of this key.
+    ///
+    /// For v4 keys this is a SHA-1 hash over [`Self::serialize_for_hashing`]; for v6 keys
+    /// it is the full-length SHA-256 hash over the same, carried in full (32 bytes)
+    /// rather than truncated.
     fn fingerprint(&self) -> Fingerprint;
 
     /// Returns the Key ID of the associated primary key.
+    ///
+    /// Unlike [`Self::fingerprint`], this is always 8 bytes (the low-order 64 bits of
+    /// the fingerprint) regardless of key version, so v6 support needs no change here.
     fn key_id(&self) -> KeyId;
 
     fn algorithm(&self) -> PublicKeyAlgorithm;
@@ -23,39 +33,711 @@ pub trait PublicKeyTrait: std::fmt::Debug {
     fn expiration(&self) -> Option<u16>;
 
     /// Verify a signed message.
-    /// Data will be hashed using `hash`, before verifying.
+    /// Data will be hashed using `hash`, before verifying, through [`DefaultCryptoBackend`]'s
+    /// [`CryptoBackend::hash`]; the `backend-rustcrypto` feature selects which backend that is.
+    /// A key type that embeds its own backend can override this default.
     fn verify_signature(
         &self,
         hash: HashAlgorithm,
         data: &[u8],
         sig: &SignatureBytes,
-    ) -> Result<()>;
+    ) -> Result<()> {
+        let digest = DefaultCryptoBackend::default().hash(hash, data)?;
+        self.verify_digest(hash, &digest, sig)
+    }
+
+    /// Verify a signature made over an already-finalized `digest`, produced using `hash`.
+    ///
+    /// This is the lower-level counterpart to [`Self::verify_signature`]: it performs
+    /// only the asymmetric check, so callers that stream-hash arbitrarily large input
+    /// (or verify against a digest produced by a hardware token) don't need to hold
+    /// the whole message in memory.
+    ///
+    /// Dispatches through [`DefaultCryptoBackend`]; returns `Err(Error::Unsupported(..))`
+    /// for an algorithm the backend does not recognize, rather than panicking. A key
+    /// type that embeds its own backend can override this default.
+    fn verify_digest(
+        &self,
+        hash: HashAlgorithm,
+        digest: &[u8],
+        sig: &SignatureBytes,
+    ) -> Result<()> {
+        DefaultCryptoBackend::default().verify_signature(
+            self.public_params(),
+            self.algorithm(),
+            hash,
+            digest,
+            sig,
+        )
+    }
 
     /// Encrypt the given `plain` for this key.
+    ///
+    /// Implementations should dispatch the asymmetric operation through a
+    /// [`CryptoBackend`] (see [`DefaultCryptoBackend`]) and return
+    /// `Err(Error::Unsupported(..))` for an algorithm the backend does not recognize,
+    /// rather than panicking.
     fn encrypt<R: CryptoRng + Rng>(&self, rng: R, plain: &[u8], typ: EskType)
         -> Result<PkeskBytes>;
 
     // TODO: figure out a better place for this
     /// This is the data used for hashing in a signature. Only uses the public portion of the key.
+    ///
+    /// For v6 keys this must emit the v6 framing described by [`write_v6_fingerprint_header`]
+    /// (the `0x9b` packet tag followed by a 4-octet big-endian length of the key material)
+    /// ahead of the key material itself, rather than the unprefixed v4 framing.
     fn serialize_for_hashing(&self, writer: &mut impl io::Write) -> Result<()>;
 
     fn public_params(&self) -> &PublicParams;
 
+    /// Whether this key's algorithm is known to support signing.
+    ///
+    /// Unlike [`Self::is_signing_key`], this distinguishes "definitely cannot sign"
+    /// ([`KeyCapability::No`]) from "the algorithm ID is not one this crate recognizes,
+    /// so it cannot be decided" ([`KeyCapability::Unknown`]) — relevant for newly
+    /// standardized or private-use (100-110) algorithm IDs.
+    fn signing_capability(&self) -> KeyCapability {
+        capability_for_signing(self.algorithm())
+    }
+
+    /// Whether this key's algorithm is known to support encryption.
+    ///
+    /// Unlike [`Self::is_encryption_key`], this distinguishes "definitely cannot
+    /// encrypt" ([`KeyCapability::No`]) from "the algorithm ID is not one this crate
+    /// recognizes, so it cannot be decided" ([`KeyCapability::Unknown`]) — relevant
+    /// for newly standardized or private-use (100-110) algorithm IDs.
+    fn encryption_capability(&self) -> KeyCapability {
+        capability_for_encryption(self.algorithm())
+    }
+
+    /// Whether this key's algorithm is known to support signing.
+    ///
+    /// Treats [`KeyCapability::Unknown`] as `false`; use [`Self::signing_capability`]
+    /// to distinguish an unrecognized algorithm from one that definitely cannot sign.
     fn is_signing_key(&self) -> bool {
-        use crate::crypto::public_key::PublicKeyAlgorithm::*;
-        matches!(
-            self.algorithm(),
-            RSA | RSASign | Elgamal | DSA | ECDSA | EdDSALegacy | Ed25519 | Ed448
-        )
+        self.signing_capability() == KeyCapability::Yes
     }
 
+    /// Whether this key's algorithm is known to support encryption.
+    ///
+    /// Treats [`KeyCapability::Unknown`] as `false`; use [`Self::encryption_capability`]
+    /// to distinguish an unrecognized algorithm from one that definitely cannot encrypt.
     fn is_encryption_key(&self) -> bool {
-        use crate::crypto::public_key::PublicKeyAlgorithm::*;
+        self.encryption_capability() == KeyCapability::Yes
+    }
+}
 
-        matches!(
-            self.algorithm(),
-            RSA | RSAEncrypt | ECDH | DiffieHellman | Elgamal | ElgamalEncrypt | X25519 | X448
-        )
+/// The result of a capability query on a [`PublicKeyTrait::algorithm`].
+///
+/// Distinguishes "definitely does (not) support this" from "the algorithm ID is not
+/// one this crate recognizes, so it cannot be decided" — the latter covers newly
+/// standardized algorithms and the private-use (100-110) range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyCapability {
+    Yes,
+    No,
+    Unknown,
+}
+
+impl KeyCapability {
+    /// `true` if the algorithm is known to support the capability.
+    pub fn is_yes(self) -> bool {
+        self == KeyCapability::Yes
+    }
+
+    /// `true` if the algorithm ID was not recognized, so the capability could not be decided.
+    pub fn is_unknown(self) -> bool {
+        self == KeyCapability::Unknown
+    }
+}
+
+/// Pure mapping behind [`PublicKeyTrait::signing_capability`], pulled out of the trait
+/// so it's testable against every [`PublicKeyAlgorithm`] variant without a concrete key.
+fn capability_for_signing(algorithm: PublicKeyAlgorithm) -> KeyCapability {
+    use crate::crypto::public_key::PublicKeyAlgorithm::*;
+    match algorithm {
+        RSA | RSASign | Elgamal | DSA | ECDSA | EdDSALegacy | Ed25519 | Ed448 => KeyCapability::Yes,
+        RSAEncrypt | ECDH | DiffieHellman | ElgamalEncrypt | X25519 | X448 => KeyCapability::No,
+        Private(_) | Unknown(_) => KeyCapability::Unknown,
+    }
+}
+
+/// Pure mapping behind [`PublicKeyTrait::encryption_capability`], pulled out of the trait
+/// so it's testable against every [`PublicKeyAlgorithm`] variant without a concrete key.
+fn capability_for_encryption(algorithm: PublicKeyAlgorithm) -> KeyCapability {
+    use crate::crypto::public_key::PublicKeyAlgorithm::*;
+    match algorithm {
+        RSA | RSAEncrypt | ECDH | DiffieHellman | Elgamal | ElgamalEncrypt | X25519 | X448 => {
+            KeyCapability::Yes
+        }
+        RSASign | DSA | ECDSA | EdDSALegacy | Ed25519 | Ed448 => KeyCapability::No,
+        Private(_) | Unknown(_) => KeyCapability::Unknown,
+    }
+}
+
+#[cfg(test)]
+mod algorithm_capability_tests {
+    use super::{capability_for_encryption, capability_for_signing, KeyCapability};
+    use crate::crypto::public_key::PublicKeyAlgorithm;
+
+    #[test]
+    fn private_and_unknown_algorithms_cannot_be_decided() {
+        for algorithm in [PublicKeyAlgorithm::Private(105), PublicKeyAlgorithm::Unknown(250)] {
+            assert_eq!(capability_for_signing(algorithm), KeyCapability::Unknown);
+            assert_eq!(capability_for_encryption(algorithm), KeyCapability::Unknown);
+        }
+    }
+
+    #[test]
+    fn known_signing_only_algorithms_cannot_encrypt() {
+        assert_eq!(capability_for_signing(PublicKeyAlgorithm::Ed25519), KeyCapability::Yes);
+        assert_eq!(capability_for_encryption(PublicKeyAlgorithm::Ed25519), KeyCapability::No);
+    }
+
+    #[test]
+    fn known_encryption_only_algorithms_cannot_sign() {
+        assert_eq!(capability_for_signing(PublicKeyAlgorithm::X25519), KeyCapability::No);
+        assert_eq!(capability_for_encryption(PublicKeyAlgorithm::X25519), KeyCapability::Yes);
+    }
+}
+
+#[cfg(test)]
+mod key_capability_tests {
+    use super::KeyCapability;
+
+    #[test]
+    fn is_yes_only_matches_yes() {
+        assert!(KeyCapability::Yes.is_yes());
+        assert!(!KeyCapability::No.is_yes());
+        assert!(!KeyCapability::Unknown.is_yes());
+    }
+
+    #[test]
+    fn is_unknown_only_matches_unknown() {
+        assert!(KeyCapability::Unknown.is_unknown());
+        assert!(!KeyCapability::Yes.is_unknown());
+        assert!(!KeyCapability::No.is_unknown());
+    }
+}
+
+/// A signing key whose secret material may live outside this process, e.g. on a
+/// smart card, a TPM, or behind a remote agent.
+///
+/// Operates purely on an already-computed digest, so implementors only need to
+/// perform the asymmetric signing primitive itself.
+///
+/// Message-building and detached-signature call sites should accept `&mut dyn Signer`
+/// rather than a concrete secret key; see [`sign_message`] for the call site this
+/// threads through.
+pub trait Signer {
+    /// The public half of this key.
+    fn public(&self) -> &dyn PublicKeyTrait;
+
+    /// Sign `digest`, which was produced using `hash`.
+    fn sign(&mut self, hash: HashAlgorithm, digest: &[u8]) -> Result<SignatureBytes>;
+}
+
+/// A decryption key whose secret material may live outside this process, e.g. on a
+/// smart card, a TPM, or behind a remote agent.
+///
+/// Operates purely on the already-parsed `PkeskBytes`, so implementors only need to
+/// perform the asymmetric decryption primitive itself.
+///
+/// Message-decryption call sites should accept `&mut dyn Decryptor` rather than a
+/// concrete secret key; see [`decrypt_session_key`] for the call site this threads
+/// through.
+pub trait Decryptor {
+    /// The public half of this key.
+    fn public(&self) -> &dyn PublicKeyTrait;
+
+    /// Decrypt `ciphertext`, producing the session key it carries.
+    fn decrypt(&mut self, ciphertext: &PkeskBytes, esk_type: EskType) -> Result<Vec<u8>>;
+}
+
+impl Signer for Box<dyn Signer + '_> {
+    fn public(&self) -> &dyn PublicKeyTrait {
+        (**self).public()
+    }
+
+    fn sign(&mut self, hash: HashAlgorithm, digest: &[u8]) -> Result<SignatureBytes> {
+        (**self).sign(hash, digest)
+    }
+}
+
+impl Decryptor for Box<dyn Decryptor + '_> {
+    fn public(&self) -> &dyn PublicKeyTrait {
+        (**self).public()
+    }
+
+    fn decrypt(&mut self, ciphertext: &PkeskBytes, esk_type: EskType) -> Result<Vec<u8>> {
+        (**self).decrypt(ciphertext, esk_type)
+    }
+}
+
+/// Adapts an in-memory [`SecretKeyTrait`] key into the [`Signer`] interface.
+pub struct InMemorySigner<S> {
+    secret_key: S,
+}
+
+impl<S> InMemorySigner<S> {
+    pub fn new(secret_key: S) -> Self {
+        Self { secret_key }
+    }
+}
+
+impl<S: SecretKeyTrait + PublicKeyTrait> Signer for InMemorySigner<S> {
+    fn public(&self) -> &dyn PublicKeyTrait {
+        &self.secret_key
+    }
+
+    fn sign(&mut self, hash: HashAlgorithm, digest: &[u8]) -> Result<SignatureBytes> {
+        self.secret_key.create_signature(hash, digest)
+    }
+}
+
+/// Adapts an in-memory [`SecretKeyTrait`] key into the [`Decryptor`] interface.
+pub struct InMemoryDecryptor<S> {
+    secret_key: S,
+}
+
+impl<S> InMemoryDecryptor<S> {
+    pub fn new(secret_key: S) -> Self {
+        Self { secret_key }
+    }
+}
+
+impl<S: SecretKeyTrait + PublicKeyTrait> Decryptor for InMemoryDecryptor<S> {
+    fn public(&self) -> &dyn PublicKeyTrait {
+        &self.secret_key
+    }
+
+    fn decrypt(&mut self, ciphertext: &PkeskBytes, esk_type: EskType) -> Result<Vec<u8>> {
+        self.secret_key.decrypt(ciphertext, esk_type)
+    }
+}
+
+/// Hashes `data` with `hash` and signs the resulting digest using `signer`.
+///
+/// This is the call site [`Signer`] exists for: message-building code should hold a
+/// `&mut dyn Signer` here rather than a concrete [`SecretKeyTrait`] implementor, so the
+/// secret key may live on a smart card or behind a remote agent instead of in process
+/// memory. Pass an [`InMemorySigner`] to keep using an in-memory key unchanged.
+pub fn sign_message(
+    signer: &mut dyn Signer,
+    hash: HashAlgorithm,
+    data: &[u8],
+) -> Result<SignatureBytes> {
+    let digest = DefaultCryptoBackend::default().hash(hash, data)?;
+    signer.sign(hash, &digest)
+}
+
+/// Decrypts `ciphertext` using `decryptor`, producing the session key it carries.
+///
+/// This is the call site [`Decryptor`] exists for: message-decryption code should hold
+/// a `&mut dyn Decryptor` here rather than a concrete [`SecretKeyTrait`] implementor.
+/// Pass an [`InMemoryDecryptor`] to keep using an in-memory key unchanged.
+pub fn decrypt_session_key(
+    decryptor: &mut dyn Decryptor,
+    ciphertext: &PkeskBytes,
+    esk_type: EskType,
+) -> Result<Vec<u8>> {
+    decryptor.decrypt(ciphertext, esk_type)
+}
+
+/// Centralizes the cryptographic primitives invoked by [`PublicKeyTrait::verify_signature`]
+/// and [`PublicKeyTrait::encrypt`] (plus the symmetric/AEAD and hash primitives they rely
+/// on), so an alternative, pure-Rust provider (e.g. for WebAssembly or audited-free
+/// builds) can be swapped in for [`DefaultCryptoBackend`] without touching call sites.
+///
+/// An implementation that does not support a given `(algorithm, backend)` pair must
+/// return [`crate::errors::Error::Unsupported`] rather than panicking, so a build that
+/// lacks, say, NIST-curve ECDSA degrades gracefully instead of aborting.
+pub trait CryptoBackend {
+    /// Verify a signature over `digest` for the given public key parameters.
+    fn verify_signature(
+        &self,
+        public_params: &PublicParams,
+        algorithm: PublicKeyAlgorithm,
+        hash: HashAlgorithm,
+        digest: &[u8],
+        sig: &SignatureBytes,
+    ) -> Result<()>;
+
+    /// Encrypt `plain` under the given public key parameters.
+    fn encrypt<R: CryptoRng + Rng>(
+        &self,
+        rng: R,
+        public_params: &PublicParams,
+        algorithm: PublicKeyAlgorithm,
+        plain: &[u8],
+        typ: EskType,
+    ) -> Result<PkeskBytes>;
+
+    /// Compute the digest of `data` using `hash`.
+    fn hash(&self, hash: HashAlgorithm, data: &[u8]) -> Result<Vec<u8>>;
+
+    /// Encrypt `plain` with an AEAD construction (e.g. AES/EAX) under `alg`, `key`,
+    /// `nonce`, and `aad`. The returned bytes carry the authentication tag.
+    fn encrypt_aead(
+        &self,
+        alg: SymmetricKeyAlgorithm,
+        key: &[u8],
+        nonce: &[u8],
+        aad: &[u8],
+        plain: &[u8],
+    ) -> Result<Vec<u8>>;
+
+    /// Decrypt `ciphertext` (authentication tag included) with an AEAD construction
+    /// under `alg`, `key`, `nonce`, and `aad`.
+    fn decrypt_aead(
+        &self,
+        alg: SymmetricKeyAlgorithm,
+        key: &[u8],
+        nonce: &[u8],
+        aad: &[u8],
+        ciphertext: &[u8],
+    ) -> Result<Vec<u8>>;
+}
+
+/// The backend selected by default.
+///
+/// Built with `--features backend-rustcrypto`, this is [`RustCryptoBackend`] instead of
+/// [`NativeBackend`] — a consumer who needs a different provider still implements
+/// [`CryptoBackend`] for their own type and routes call sites through that instead of
+/// this alias.
+#[cfg(not(feature = "backend-rustcrypto"))]
+pub type DefaultCryptoBackend = NativeBackend;
+
+/// The backend selected by default when built with the `backend-rustcrypto` feature.
+/// See [`DefaultCryptoBackend`].
+#[cfg(feature = "backend-rustcrypto")]
+pub type DefaultCryptoBackend = RustCryptoBackend;
+
+/// The [`CryptoBackend`] this crate ships by default: calls directly into this crate's
+/// own asymmetric, symmetric, and hash primitives.
+///
+/// Algorithm IDs not handled below return `Err(Error::Unsupported(..))` rather than
+/// panicking — in particular every [`PublicKeyAlgorithm::Private`]/
+/// [`PublicKeyAlgorithm::Unknown`] ID falls through to this, so an unrecognized or
+/// private-use algorithm degrades gracefully instead of aborting.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NativeBackend;
+
+impl CryptoBackend for NativeBackend {
+    fn verify_signature(
+        &self,
+        public_params: &PublicParams,
+        algorithm: PublicKeyAlgorithm,
+        hash: HashAlgorithm,
+        digest: &[u8],
+        sig: &SignatureBytes,
+    ) -> Result<()> {
+        match algorithm {
+            PublicKeyAlgorithm::RSA | PublicKeyAlgorithm::RSASign => {
+                crate::crypto::rsa::verify(public_params, hash, digest, sig)
+            }
+            PublicKeyAlgorithm::DSA => crate::crypto::dsa::verify(public_params, hash, digest, sig),
+            PublicKeyAlgorithm::ECDSA => {
+                crate::crypto::ecdsa::verify(public_params, hash, digest, sig)
+            }
+            PublicKeyAlgorithm::EdDSALegacy => {
+                crate::crypto::eddsa_legacy::verify(public_params, digest, sig)
+            }
+            PublicKeyAlgorithm::Ed25519 => {
+                crate::crypto::ed25519::verify(public_params, digest, sig)
+            }
+            PublicKeyAlgorithm::Ed448 => crate::crypto::ed448::verify(public_params, digest, sig),
+            other => Err(crate::errors::Error::Unsupported(format!(
+                "verify_signature is not supported for {other:?}"
+            ))),
+        }
+    }
+
+    fn encrypt<R: CryptoRng + Rng>(
+        &self,
+        rng: R,
+        public_params: &PublicParams,
+        algorithm: PublicKeyAlgorithm,
+        plain: &[u8],
+        typ: EskType,
+    ) -> Result<PkeskBytes> {
+        match algorithm {
+            PublicKeyAlgorithm::RSA | PublicKeyAlgorithm::RSAEncrypt => {
+                crate::crypto::rsa::encrypt(rng, public_params, plain)
+            }
+            PublicKeyAlgorithm::ECDH => {
+                crate::crypto::ecdh::encrypt(rng, public_params, plain, typ)
+            }
+            PublicKeyAlgorithm::X25519 => crate::crypto::x25519::encrypt(rng, public_params, plain),
+            PublicKeyAlgorithm::X448 => crate::crypto::x448::encrypt(rng, public_params, plain),
+            other => Err(crate::errors::Error::Unsupported(format!(
+                "encrypt is not supported for {other:?}"
+            ))),
+        }
+    }
+
+    fn hash(&self, hash: HashAlgorithm, data: &[u8]) -> Result<Vec<u8>> {
+        hash.digest(data)
+    }
+
+    fn encrypt_aead(
+        &self,
+        alg: SymmetricKeyAlgorithm,
+        key: &[u8],
+        nonce: &[u8],
+        aad: &[u8],
+        plain: &[u8],
+    ) -> Result<Vec<u8>> {
+        match alg {
+            SymmetricKeyAlgorithm::AES128
+            | SymmetricKeyAlgorithm::AES192
+            | SymmetricKeyAlgorithm::AES256 => {
+                crate::crypto::eax::encrypt(alg, key, nonce, aad, plain)
+            }
+            other => Err(crate::errors::Error::Unsupported(format!(
+                "encrypt_aead is not supported for {other:?}"
+            ))),
+        }
+    }
+
+    fn decrypt_aead(
+        &self,
+        alg: SymmetricKeyAlgorithm,
+        key: &[u8],
+        nonce: &[u8],
+        aad: &[u8],
+        ciphertext: &[u8],
+    ) -> Result<Vec<u8>> {
+        match alg {
+            SymmetricKeyAlgorithm::AES128
+            | SymmetricKeyAlgorithm::AES192
+            | SymmetricKeyAlgorithm::AES256 => {
+                crate::crypto::eax::decrypt(alg, key, nonce, aad, ciphertext)
+            }
+            other => Err(crate::errors::Error::Unsupported(format!(
+                "decrypt_aead is not supported for {other:?}"
+            ))),
+        }
+    }
+}
+
+/// A pure-Rust [`CryptoBackend`] built entirely on RustCrypto crates (`rsa`, `dsa`,
+/// `ed25519-dalek`, `x25519-dalek`, `aes-eax`, `sha2`/`sha1`/`md-5`), with no dependency
+/// on a C library such as OpenSSL or libsodium. Enabled via the `backend-rustcrypto`
+/// feature; see [`DefaultCryptoBackend`].
+///
+/// Covers fewer algorithms than [`NativeBackend`]: ECDSA and Ed448 signatures and X448
+/// encryption are not handled here. Those, like every other unsupported
+/// `(algorithm, backend)` pair, return `Err(Error::Unsupported(..))` rather than panicking.
+#[cfg(feature = "backend-rustcrypto")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RustCryptoBackend;
+
+#[cfg(feature = "backend-rustcrypto")]
+impl CryptoBackend for RustCryptoBackend {
+    fn verify_signature(
+        &self,
+        public_params: &PublicParams,
+        algorithm: PublicKeyAlgorithm,
+        hash: HashAlgorithm,
+        digest: &[u8],
+        sig: &SignatureBytes,
+    ) -> Result<()> {
+        match algorithm {
+            PublicKeyAlgorithm::RSA | PublicKeyAlgorithm::RSASign => {
+                crate::crypto::rustcrypto::rsa::verify(public_params, hash, digest, sig)
+            }
+            PublicKeyAlgorithm::DSA => {
+                crate::crypto::rustcrypto::dsa::verify(public_params, hash, digest, sig)
+            }
+            PublicKeyAlgorithm::EdDSALegacy => {
+                crate::crypto::rustcrypto::eddsa_legacy::verify(public_params, digest, sig)
+            }
+            PublicKeyAlgorithm::Ed25519 => {
+                crate::crypto::rustcrypto::ed25519::verify(public_params, digest, sig)
+            }
+            other => Err(crate::errors::Error::Unsupported(format!(
+                "verify_signature is not supported for {other:?} on the RustCrypto backend"
+            ))),
+        }
+    }
+
+    fn encrypt<R: CryptoRng + Rng>(
+        &self,
+        rng: R,
+        public_params: &PublicParams,
+        algorithm: PublicKeyAlgorithm,
+        plain: &[u8],
+        typ: EskType,
+    ) -> Result<PkeskBytes> {
+        match algorithm {
+            PublicKeyAlgorithm::RSA | PublicKeyAlgorithm::RSAEncrypt => {
+                crate::crypto::rustcrypto::rsa::encrypt(rng, public_params, plain)
+            }
+            PublicKeyAlgorithm::ECDH => {
+                crate::crypto::rustcrypto::ecdh::encrypt(rng, public_params, plain, typ)
+            }
+            PublicKeyAlgorithm::X25519 => {
+                crate::crypto::rustcrypto::x25519::encrypt(rng, public_params, plain)
+            }
+            other => Err(crate::errors::Error::Unsupported(format!(
+                "encrypt is not supported for {other:?} on the RustCrypto backend"
+            ))),
+        }
+    }
+
+    fn hash(&self, hash: HashAlgorithm, data: &[u8]) -> Result<Vec<u8>> {
+        crate::crypto::rustcrypto::hash::digest(hash, data)
+    }
+
+    fn encrypt_aead(
+        &self,
+        alg: SymmetricKeyAlgorithm,
+        key: &[u8],
+        nonce: &[u8],
+        aad: &[u8],
+        plain: &[u8],
+    ) -> Result<Vec<u8>> {
+        match alg {
+            SymmetricKeyAlgorithm::AES128
+            | SymmetricKeyAlgorithm::AES192
+            | SymmetricKeyAlgorithm::AES256 => {
+                crate::crypto::rustcrypto::eax::encrypt(alg, key, nonce, aad, plain)
+            }
+            other => Err(crate::errors::Error::Unsupported(format!(
+                "encrypt_aead is not supported for {other:?} on the RustCrypto backend"
+            ))),
+        }
+    }
+
+    fn decrypt_aead(
+        &self,
+        alg: SymmetricKeyAlgorithm,
+        key: &[u8],
+        nonce: &[u8],
+        aad: &[u8],
+        ciphertext: &[u8],
+    ) -> Result<Vec<u8>> {
+        match alg {
+            SymmetricKeyAlgorithm::AES128
+            | SymmetricKeyAlgorithm::AES192
+            | SymmetricKeyAlgorithm::AES256 => {
+                crate::crypto::rustcrypto::eax::decrypt(alg, key, nonce, aad, ciphertext)
+            }
+            other => Err(crate::errors::Error::Unsupported(format!(
+                "decrypt_aead is not supported for {other:?} on the RustCrypto backend"
+            ))),
+        }
+    }
+}
+
+/// Writes the v6 fingerprint framing ahead of the key material: the `0x9b` packet tag
+/// octet followed by the 4-octet big-endian length of what follows.
+///
+/// Unlike v4, where [`PublicKeyTrait::serialize_for_hashing`] hashes the key material
+/// with no length prefix, v6 fingerprints (and the signatures made over v6 keys) are
+/// computed over the key material framed this way.
+pub fn write_v6_fingerprint_header(
+    writer: &mut impl io::Write,
+    key_material_len: u32,
+) -> Result<()> {
+    writer.write_all(&[0x9b])?;
+    writer.write_all(&key_material_len.to_be_bytes())?;
+    Ok(())
+}
+
+/// Computes the v6 fingerprint of a key from its already-serialized key material (the
+/// output of [`PublicKeyTrait::serialize_for_hashing`], minus the v6 framing).
+///
+/// A v6 `serialize_for_hashing` implementation should call [`write_v6_fingerprint_header`]
+/// followed by the key material itself; `fingerprint_v6` exists so that the same framed
+/// bytes can be hashed directly, without a `Write` implementor round-trip, when all a
+/// caller has is the key material.
+pub fn fingerprint_v6(key_material: &[u8]) -> Fingerprint {
+    use sha2::{Digest, Sha256};
+
+    let mut framed = Vec::with_capacity(5 + key_material.len());
+    // Writing to a `Vec<u8>` never fails.
+    write_v6_fingerprint_header(&mut framed, key_material.len() as u32)
+        .expect("writing the v6 framing header to a Vec<u8> is infallible");
+    framed.extend_from_slice(key_material);
+
+    Fingerprint::V6(Sha256::digest(&framed).into())
+}
+
+#[cfg(test)]
+mod v6_fingerprint_tests {
+    use super::{fingerprint_v6, write_v6_fingerprint_header, Fingerprint};
+
+    // Checks that the v6 framing (the `0x9b` tag + 4-octet big-endian length prefix)
+    // matches the octets RFC 9580 section 5.5.4 describes, independent of what the key
+    // material contains; the next test checks that `fingerprint_v6` hashes exactly those
+    // framed bytes with SHA-256, and the sample-certificate test checks a real v6 key.
+    #[test]
+    fn framing_matches_expected_prefix() {
+        let key_material: Vec<u8> = (1..=32).collect();
+
+        let mut framed = Vec::new();
+        write_v6_fingerprint_header(&mut framed, key_material.len() as u32).unwrap();
+        framed.extend_from_slice(&key_material);
+
+        assert_eq!(
+            framed,
+            hex_literal(
+                "9b000000200102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20"
+            )
+        );
+    }
+
+    #[test]
+    fn fingerprint_v6_is_sha256_of_framed_key_material() {
+        let key_material: Vec<u8> = (1..=32).collect();
+
+        let Fingerprint::V6(digest) = fingerprint_v6(&key_material) else {
+            panic!("fingerprint_v6 must return a Fingerprint::V6");
+        };
+
+        assert_eq!(
+            digest.to_vec(),
+            hex_literal("7c9ce3de0692f6d22dcb3262817caf250b6b433f382f7444a4d7b8f196f51bcb")
+        );
+    }
+
+    /// Round-trips `fingerprint_v6` against the published RFC 9580 Appendix A.3 sample
+    /// v6 certificate (primary key material + its fingerprint).
+    #[test]
+    fn fingerprint_v6_matches_rfc9580_sample_certificate() {
+        // Version 6, creation time, algorithm 27 (Ed25519), 4-octet key material length,
+        // then the 32-byte Ed25519 public key.
+        let key_material = hex_literal(
+            "0663877fe31b00000020f94da7bb48d60a61e567706a6587d0331999bb9d891a08242ead84543df895a3",
+        );
+
+        assert_eq!(
+            fingerprint_v6(&key_material).as_bytes(),
+            hex_literal("cb186c4f0609a697e4d52dfa6c722b0c1f1e27c18a56708f6525ec27bad9acc9")
+                .as_slice()
+        );
+    }
+
+    /// Sanity-checks the SHA-256 primitive itself against the well-known NIST test
+    /// vector for the ASCII input `"abc"`, independent of this crate's v6 framing.
+    #[test]
+    fn sha256_matches_nist_abc_vector() {
+        use sha2::{Digest, Sha256};
+
+        let digest = Sha256::digest(b"abc");
+        assert_eq!(
+            digest.to_vec(),
+            hex_literal("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+        );
+    }
+
+    fn hex_literal(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
     }
 }
 
@@ -69,6 +751,15 @@ impl<T: PublicKeyTrait> PublicKeyTrait for &T {
         (*self).verify_signature(hash, data, sig)
     }
 
+    fn verify_digest(
+        &self,
+        hash: HashAlgorithm,
+        digest: &[u8],
+        sig: &SignatureBytes,
+    ) -> Result<()> {
+        (*self).verify_digest(hash, digest, sig)
+    }
+
     fn encrypt<R: CryptoRng + Rng>(
         &self,
         rng: R,